@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+/// Render an elapsed time given in microseconds as a short human-readable
+/// string, picking the unit by magnitude.
+///
+/// Values are rounded before the unit is chosen, so anything that rounds up
+/// to the next unit is shown in it (`999_999` is `1s`, not `1000ms`).
+/// Milliseconds and seconds keep up to two decimals; from one minute on the
+/// value is rounded to whole seconds and split into `m`/`s`, with hours as the
+/// largest unit.
+///
+/// ```
+/// use log_rs::format_duration_us;
+///
+/// assert_eq!(format_duration_us(850), "850µs");
+/// assert_eq!(format_duration_us(1_234), "1.23ms");
+/// assert_eq!(format_duration_us(4_500_000), "4.5s");
+/// assert_eq!(format_duration_us(123_000_000), "2m3s");
+/// assert_eq!(format_duration_us(3_723_000_000), "1h2m3s");
+/// assert_eq!(format_duration_us(-1_500), "-1.5ms");
+/// ```
+pub fn format_duration_us(us: i64) -> String {
+    let sign = if us < 0 { "-" } else { "" };
+    let us = us.unsigned_abs();
+
+    let body = if us < 1_000 {
+        format!("{us}µs")
+    } else if (us + 5) / 10 < 100_000 {
+        format!("{}ms", format_hundredths((us + 5) / 10))
+    } else if (us + 5_000) / 10_000 < 6_000 {
+        format!("{}s", format_hundredths((us + 5_000) / 10_000))
+    } else {
+        let secs = (us + 500_000) / 1_000_000;
+        match secs / 3_600 {
+            0 => format!("{}m{}s", secs / 60, secs % 60),
+            hours => format!("{hours}h{}m{}s", secs / 60 % 60, secs % 60),
+        }
+    };
+
+    format!("{sign}{body}")
+}
+
+/// Same as [`format_duration_us`], taking a [`Duration`].
+///
+/// ```
+/// use std::time::Duration;
+/// use log_rs::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_millis(12)), "12ms");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    format_duration_us(duration.as_micros().min(i64::MAX as u128) as i64)
+}
+
+fn format_hundredths(hundredths: u64) -> String {
+    let (whole, frac) = (hundredths / 100, hundredths % 100);
+
+    match frac {
+        0 => format!("{whole}"),
+        f if f % 10 == 0 => format!("{whole}.{}", f / 10),
+        f => format!("{whole}.{f:02}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn microseconds_to_milliseconds_edge() {
+        assert_eq!(format_duration_us(0), "0µs");
+        assert_eq!(format_duration_us(999), "999µs");
+        assert_eq!(format_duration_us(1_000), "1ms");
+        assert_eq!(format_duration_us(1_005), "1.01ms");
+        assert_eq!(format_duration_us(1_010), "1.01ms");
+    }
+
+    #[test]
+    fn milliseconds_to_seconds_edge() {
+        assert_eq!(format_duration_us(999_994), "999.99ms");
+        assert_eq!(format_duration_us(999_995), "1s");
+        assert_eq!(format_duration_us(999_999), "1s");
+        assert_eq!(format_duration_us(1_000_000), "1s");
+    }
+
+    #[test]
+    fn seconds_to_minutes_edge() {
+        assert_eq!(format_duration_us(59_994_999), "59.99s");
+        assert_eq!(format_duration_us(59_995_000), "1m0s");
+        assert_eq!(format_duration_us(59_999_994), "1m0s");
+        assert_eq!(format_duration_us(59_999_999), "1m0s");
+        assert_eq!(format_duration_us(60_000_000), "1m0s");
+    }
+
+    #[test]
+    fn minutes_to_hours_edge() {
+        assert_eq!(format_duration_us(3_599_499_999), "59m59s");
+        assert_eq!(format_duration_us(3_599_500_000), "1h0m0s");
+        assert_eq!(format_duration_us(3_600_000_000), "1h0m0s");
+    }
+
+    #[test]
+    fn extremes() {
+        assert_eq!(format_duration_us(i64::MIN), "-2562047788h0m55s");
+        assert_eq!(format_duration_us(i64::MAX), "2562047788h0m55s");
+        assert_eq!(format_duration_us(-999), "-999µs");
+    }
+
+    #[test]
+    fn duration_above_i64_max_saturates() {
+        let huge = Duration::from_secs(u64::MAX);
+        assert_eq!(format_duration(huge), format_duration_us(i64::MAX));
+        assert_eq!(format_duration(Duration::from_micros(999_999)), "1s");
+    }
+}